@@ -0,0 +1,28 @@
+use alloc::format;
+use alloc::string::String;
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    Parse(String),
+    Eval(String),
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+impl From<nom::Err<nom::error::Error<String>>> for Error {
+    fn from(err: nom::Err<nom::error::Error<String>>) -> Self {
+        Error::Parse(format!("{:?}", err))
+    }
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Error::Eval(message)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Error::Eval(message.into())
+    }
+}
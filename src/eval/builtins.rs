@@ -0,0 +1,70 @@
+use alloc::vec::Vec;
+use crate::error::*;
+use crate::eval::context::Context;
+use crate::{DataSource, Object};
+
+/// Installs the spreadsheet-style aggregates (`sum`, `avg`, `count`, `min`,
+/// `max`) as globals, the same way `test_call` wires up a one-off `sum`
+/// global by hand. `Context::new` calls this so every context gets them
+/// for free, giving users formulas like `avg({price})` over a whole column.
+pub(crate) fn install<P: DataSource>(context: Context<P>) -> Context<P> {
+    context
+        .with_global("sum", Object::function(sum))
+        .with_global("avg", Object::function(avg))
+        .with_global("count", Object::function(count))
+        .with_global("min", Object::function(min))
+        .with_global("max", Object::function(max))
+}
+
+fn sum(args: Vec<Object>) -> Result<Object> {
+    Ok(Object::Number(numbers(args)?.into_iter().sum()))
+}
+
+fn avg(args: Vec<Object>) -> Result<Object> {
+    let values = numbers(args)?;
+
+    if values.is_empty() {
+        return Err(Error::Eval("avg of an empty column is undefined".into()));
+    }
+
+    Ok(Object::Number(values.iter().sum::<f64>() / values.len() as f64))
+}
+
+fn count(args: Vec<Object>) -> Result<Object> {
+    Ok(Object::Number(list(args)?.len() as f64))
+}
+
+fn min(args: Vec<Object>) -> Result<Object> {
+    reduce(args, f64::min)
+}
+
+fn max(args: Vec<Object>) -> Result<Object> {
+    reduce(args, f64::max)
+}
+
+fn reduce(args: Vec<Object>, f: impl Fn(f64, f64) -> f64) -> Result<Object> {
+    numbers(args)?
+        .into_iter()
+        .reduce(f)
+        .map(Object::Number)
+        .ok_or_else(|| Error::Eval("aggregate of an empty column is undefined".into()))
+}
+
+fn list(args: Vec<Object>) -> Result<Vec<Object>> {
+    match args.into_iter().next() {
+        Some(Object::List(values)) => Ok(values),
+        _ => Err(Error::Eval("aggregate builtins expect a single list argument".into())),
+    }
+}
+
+/// Non-`Number` elements are a hard error rather than being skipped silently,
+/// so a stray string in a column surfaces immediately instead of skewing the result.
+fn numbers(args: Vec<Object>) -> Result<Vec<f64>> {
+    list(args)?
+        .into_iter()
+        .map(|value| match value {
+            Object::Number(n) => Ok(n),
+            _ => Err(Error::Eval("aggregate builtins only operate on numbers".into())),
+        })
+        .collect()
+}
@@ -0,0 +1,150 @@
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use crate::error::*;
+use crate::parse::parser;
+use crate::Object;
+use nom::IResult;
+
+const TAG_NUMBER: u8 = 0;
+const TAG_STRING: u8 = 1;
+const TAG_LIST: u8 = 2;
+const TAG_ASSOCIATIVE_ARRAY: u8 = 3;
+const TAG_RATIONAL: u8 = 4;
+const TAG_COMPLEX: u8 = 5;
+
+impl Object {
+    /// A self-describing, length-prefixed encoding: a one-byte type tag
+    /// followed by the payload. Numbers write their raw `f64` bits; strings
+    /// write a decimal length, `:`, then the raw bytes; lists and
+    /// associative arrays write an element count the same way, followed by
+    /// their encoded elements (and keys, for associative arrays). Rationals
+    /// write both `i64` halves as big-endian bytes, and complexes write both
+    /// `f64` halves the same way numbers do. `Object::Function` has no byte
+    /// representation, so it's the one variant `encode` fails on.
+    ///
+    /// `pub` so a host can serialize the result of `Context::evaluate` and
+    /// send it elsewhere -- `decode` rehydrates it on the other end.
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut out = Vec::new();
+
+        match self {
+            Object::Number(n) => {
+                out.push(TAG_NUMBER);
+                out.extend_from_slice(&n.to_bits().to_be_bytes());
+            }
+            Object::String(s) => {
+                out.push(TAG_STRING);
+                encode_bytes(s.as_bytes(), &mut out);
+            }
+            Object::List(items) => {
+                out.push(TAG_LIST);
+                out.extend_from_slice(format!("{}:", items.len()).as_bytes());
+
+                for item in items {
+                    out.extend(item.encode()?);
+                }
+            }
+            Object::AssociativeArray(map) => {
+                out.push(TAG_ASSOCIATIVE_ARRAY);
+                out.extend_from_slice(format!("{}:", map.len()).as_bytes());
+
+                for (key, value) in map {
+                    encode_bytes(key.as_bytes(), &mut out);
+                    out.extend(value.encode()?);
+                }
+            }
+            Object::Rational(n, d) => {
+                out.push(TAG_RATIONAL);
+                out.extend_from_slice(&n.to_be_bytes());
+                out.extend_from_slice(&d.to_be_bytes());
+            }
+            Object::Complex(re, im) => {
+                out.push(TAG_COMPLEX);
+                out.extend_from_slice(&re.to_bits().to_be_bytes());
+                out.extend_from_slice(&im.to_bits().to_be_bytes());
+            }
+            Object::Function(_) => {
+                return Err(Error::Eval("a function has no byte encoding".into()));
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Reuses the crate's `nom` parser infrastructure to decode the format
+    /// written by `encode`, round-tripping nested lists and associative arrays.
+    pub fn decode(input: &[u8]) -> IResult<&[u8], Object> {
+        let (input, tag) = parser::u8(input)?;
+
+        match tag {
+            TAG_NUMBER => {
+                let (input, bits) = parser::be_u64(input)?;
+                Ok((input, Object::Number(f64::from_bits(bits))))
+            }
+            TAG_STRING => {
+                let (input, bytes) = decode_bytes(input)?;
+                Ok((input, Object::String(String::from_utf8_lossy(bytes).into_owned())))
+            }
+            TAG_LIST => {
+                let (mut rest, count) = decode_length(input)?;
+                let mut items = Vec::with_capacity(count);
+
+                for _ in 0..count {
+                    let (next, item) = Object::decode(rest)?;
+                    items.push(item);
+                    rest = next;
+                }
+
+                Ok((rest, Object::List(items)))
+            }
+            TAG_ASSOCIATIVE_ARRAY => {
+                let (mut rest, count) = decode_length(input)?;
+                let mut map = BTreeMap::new();
+
+                for _ in 0..count {
+                    let (next, key) = decode_bytes(rest)?;
+                    let (next, value) = Object::decode(next)?;
+                    map.insert(String::from_utf8_lossy(key).into_owned(), value);
+                    rest = next;
+                }
+
+                Ok((rest, Object::AssociativeArray(map)))
+            }
+            TAG_RATIONAL => {
+                let (input, n) = parser::be_i64(input)?;
+                let (input, d) = parser::be_i64(input)?;
+                Ok((input, Object::Rational(n, d)))
+            }
+            TAG_COMPLEX => {
+                let (input, re) = parser::be_u64(input)?;
+                let (input, im) = parser::be_u64(input)?;
+                Ok((input, Object::Complex(f64::from_bits(re), f64::from_bits(im))))
+            }
+            _ => Err(nom::Err::Failure(nom::error::Error::new(input, nom::error::ErrorKind::Tag))),
+        }
+    }
+}
+
+fn encode_bytes(bytes: &[u8], out: &mut Vec<u8>) {
+    out.extend_from_slice(format!("{}:", bytes.len()).as_bytes());
+    out.extend_from_slice(bytes);
+}
+
+fn decode_bytes(input: &[u8]) -> IResult<&[u8], &[u8]> {
+    let (input, len) = decode_length(input)?;
+    parser::take(len)(input)
+}
+
+fn decode_length(input: &[u8]) -> IResult<&[u8], usize> {
+    let (input, digits) = parser::take_till(|b| b == b':')(input)?;
+    let (input, _) = parser::tag(":")(input)?;
+
+    let len = core::str::from_utf8(digits)
+        .ok()
+        .and_then(|s| s.parse::<usize>().ok())
+        .ok_or_else(|| nom::Err::Failure(nom::error::Error::new(input, nom::error::ErrorKind::Digit)))?;
+
+    Ok((input, len))
+}
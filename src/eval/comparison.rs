@@ -0,0 +1,11 @@
+use crate::error::*;
+use crate::eval::numeric::as_number;
+use crate::Object;
+
+/// `a > b`, coerced through the same real-numeric view `numeric::add` and
+/// friends use for `Rational`/`Number`. There's no dedicated boolean
+/// `Object` variant, so the result is a `Number` -- `0.0` or `1.0` -- which
+/// is exactly what `Object::truthy` already treats as false/true.
+pub(crate) fn greater_than(a: Object, b: Object) -> Result<Object> {
+    Ok(Object::Number(if as_number(a)? > as_number(b)? { 1.0 } else { 0.0 }))
+}
@@ -0,0 +1,84 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use crate::error::*;
+use crate::eval::{builtins, comparison, numeric, pipe};
+use crate::parse::value::Value;
+use crate::{DataSource, Object};
+
+#[derive(Clone)]
+pub(crate) struct Operator {
+    pub(crate) precedence: i64,
+}
+
+/// `left op right` dispatch for a registered operator token.
+pub(crate) type BinaryOperator = fn(Object, Object) -> Result<Object>;
+
+#[derive(Clone)]
+pub struct Context<Provider: DataSource> {
+    pub(crate) provider: Provider,
+    pub(crate) globals: BTreeMap<String, Object>,
+    pub(crate) operators: BTreeMap<String, Operator>,
+    pub(crate) handlers: BTreeMap<String, BinaryOperator>,
+}
+
+impl<Provider: DataSource> Context<Provider> {
+    pub fn new(provider: Provider) -> Self {
+        let mut operators = BTreeMap::new();
+        let mut handlers: BTreeMap<String, BinaryOperator> = BTreeMap::new();
+
+        operators.insert("+".into(), Operator { precedence: 2 });
+        operators.insert("-".into(), Operator { precedence: 2 });
+        operators.insert("*".into(), Operator { precedence: 3 });
+        operators.insert("/".into(), Operator { precedence: 3 });
+        handlers.insert("+".into(), numeric::add);
+        handlers.insert("-".into(), numeric::subtract);
+        handlers.insert("*".into(), numeric::multiply);
+        handlers.insert("/".into(), numeric::divide);
+
+        // `>` binds looser than arithmetic (`p + 1 > 100` reads as
+        // `(p + 1) > 100`) but tighter than the pipe operators, so a
+        // predicate like `p -> p > 100` parses as a whole before `|?` sees it.
+        operators.insert(">".into(), Operator { precedence: 1 });
+        handlers.insert(">".into(), comparison::greater_than);
+
+        // `|>` applies, `|:` maps, `|?` filters -- all left-associative, and
+        // looser-binding than everything else so `list |: x -> x + 1` parses as
+        // `list |: (x -> x + 1)` rather than splitting the lambda body. Each
+        // handler takes `(left, right)`, i.e. `(value, function)`, matching
+        // how the operator reads: `v |> f` is `f` applied to `v`.
+        operators.insert("|>".into(), Operator { precedence: 0 });
+        operators.insert("|:".into(), Operator { precedence: 0 });
+        operators.insert("|?".into(), Operator { precedence: 0 });
+        handlers.insert("|>".into(), pipe::apply);
+        handlers.insert("|:".into(), pipe::map);
+        handlers.insert("|?".into(), pipe::filter);
+
+        builtins::install(Context {
+            provider,
+            globals: BTreeMap::new(),
+            operators,
+            handlers,
+        })
+    }
+
+    pub fn with_global(mut self, name: &str, value: Object) -> Self {
+        self.globals.insert(name.into(), value);
+        self
+    }
+
+    /// Looks up the `(left, right) -> Result<Object>` handler registered for
+    /// an operator token; the expression evaluator consults this once it has
+    /// resolved an operator token's two operands.
+    pub(crate) fn operator_handler(&self, token: &str) -> Option<&BinaryOperator> {
+        self.handlers.get(token)
+    }
+
+    pub(crate) fn evaluate_value(&self, value: &Value) -> Result<Object> {
+        value.evaluate(self)
+    }
+
+    pub fn evaluate(&self, input: &str) -> Result<Object> {
+        let value = self.parse(input)?;
+        self.evaluate_value(&value)
+    }
+}
@@ -0,0 +1,37 @@
+use alloc::format;
+use alloc::vec::Vec;
+use crate::error::*;
+use crate::eval::context::Context;
+use crate::parse::lambda::Lambda;
+use crate::{DataSource, Object};
+
+/// Turns a parsed `Lambda` into a callable `Object`, capturing the defining
+/// context's globals so nested lambdas can see outer bindings while their
+/// own parameters shadow them. The returned closure is invoked through the
+/// same `Object::function` machinery as any other callable, so `|>`/`|:`/`|?`
+/// need no special calling convention of their own.
+pub(crate) fn evaluate_lambda<P>(lambda: &Lambda, context: &Context<P>) -> Result<Object>
+where
+    P: DataSource + Clone + 'static,
+{
+    let parameters = lambda.parameters.clone();
+    let body = lambda.body.clone();
+    let captured = context.clone();
+
+    Ok(Object::function(move |args: Vec<Object>| {
+        if args.len() != parameters.len() {
+            return Err(Error::Eval(format!(
+                "expected {} argument(s), got {}",
+                parameters.len(),
+                args.len()
+            )));
+        }
+
+        let mut scope = captured.clone();
+        for (name, value) in parameters.iter().zip(args) {
+            scope = scope.with_global(name, value);
+        }
+
+        scope.evaluate_value(&body)
+    }))
+}
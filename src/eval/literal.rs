@@ -0,0 +1,120 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use crate::error::*;
+use crate::parse::literal::{Address, Column, Literal};
+use crate::{DataSource, Object, Row};
+
+/// Evaluates a parsed `Literal` against a bound data source. Numbers and
+/// strings are self-evaluating; addresses are resolved with `evaluate_address`.
+pub(crate) fn evaluate_literal<P: DataSource>(literal: &Literal, provider: &P) -> Result<Object> {
+    match literal {
+        Literal::Number(n) => Ok(Object::Number(*n)),
+        Literal::Imaginary(n) => Ok(Object::Complex(0.0, *n)),
+        Literal::String(s) => Ok(Object::String(s.clone())),
+        Literal::Name(name) => Err(Error::Eval(format!("`{}` is not bound to a value", name))),
+        Literal::Address(address) => evaluate_address(address, provider),
+    }
+}
+
+/// A bare column address (`row: None`) resolves to an `Object::List` holding
+/// that column's value for every row of the `DataSource`; a cell address
+/// (`row: Some`) resolves to the single value at that row; a range address
+/// (`end: Some`) resolves to a list of rows, each a list of cells when the
+/// range spans more than one column.
+pub(crate) fn evaluate_address<P: DataSource>(address: &Address, provider: &P) -> Result<Object> {
+    if let Some(end) = &address.end {
+        return evaluate_range(address, end, provider);
+    }
+
+    let column = column_name(&address.column);
+
+    match &address.row {
+        Some(row) => cell(&column, parse_row(row)?, provider),
+        None => {
+            let values = provider.rows()
+                .map(|row| row.get(&column).ok_or_else(|| no_such_column(&column)))
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok(Object::List(values))
+        }
+    }
+}
+
+fn evaluate_range<P: DataSource>(
+    address: &Address,
+    end: &(Column, Option<String>),
+    provider: &P,
+) -> Result<Object> {
+    let start_row = address.row.as_ref()
+        .ok_or_else(|| Error::Eval("range start is missing a row number".into()))?;
+    let end_row = end.1.as_ref()
+        .ok_or_else(|| Error::Eval("range end is missing a row number".into()))?;
+
+    let (lo, hi) = normalize(parse_row(start_row)?, parse_row(end_row)?);
+    let columns = column_range(&address.column, &end.0)?;
+
+    let rows = (lo..=hi)
+        .map(|row| {
+            let cells = columns.iter()
+                .map(|column| cell(column, row, provider))
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok(if columns.len() == 1 {
+                cells.into_iter().next().expect("columns is non-empty")
+            } else {
+                Object::List(cells)
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Object::List(rows))
+}
+
+fn normalize(a: usize, b: usize) -> (usize, usize) {
+    if a <= b { (a, b) } else { (b, a) }
+}
+
+/// Only single-letter spreadsheet columns (`A`..`Z`) expand into a range;
+/// named columns must match on both ends, collapsing to a single column.
+fn column_range(start: &Column, end: &Column) -> Result<Vec<String>> {
+    let start_name = column_name(start);
+    let end_name = column_name(end);
+
+    if start_name == end_name {
+        return Ok(alloc::vec![start_name]);
+    }
+
+    match (&start_name, &end_name) {
+        (a, b) if a.len() == 1 && b.len() == 1 => {
+            let (lo, hi) = {
+                let a = a.as_bytes()[0];
+                let b = b.as_bytes()[0];
+                if a <= b { (a, b) } else { (b, a) }
+            };
+
+            Ok((lo..=hi).map(|c| String::from(c as char)).collect())
+        }
+        _ => Err(Error::Eval("multi-letter or named column ranges are not supported".into())),
+    }
+}
+
+pub(super) fn column_name(column: &Column) -> String {
+    match column {
+        Column::Name(name) => name.clone(),
+        Column::Number(name) => name.clone(),
+    }
+}
+
+pub(super) fn parse_row(row: &str) -> Result<usize> {
+    row.parse().map_err(|_| Error::Eval(format!("invalid row index `{}`", row)))
+}
+
+pub(super) fn cell<P: DataSource>(column: &str, row: usize, provider: &P) -> Result<Object> {
+    let row = provider.row(row).ok_or_else(|| Error::Eval(format!("row {} is out of bounds", row)))?;
+    row.get(column).ok_or_else(|| no_such_column(column))
+}
+
+fn no_such_column(column: &str) -> Error {
+    Error::Eval(format!("no column `{}`", column))
+}
@@ -0,0 +1,14 @@
+pub(crate) mod test;
+pub(crate) mod provider;
+pub(crate) mod object;
+pub(crate) mod context;
+pub(crate) mod operators;
+pub(crate) mod literal;
+pub(crate) mod builtins;
+pub(crate) mod lambda;
+pub(crate) mod pipe;
+pub(crate) mod numeric;
+pub(crate) mod comparison;
+pub(crate) mod codec;
+
+pub(crate) use object::Object;
@@ -0,0 +1,131 @@
+use crate::error::*;
+use crate::Object;
+
+/// `Rational -> Number -> Complex`: arithmetic between two rationals stays
+/// exact, mixing in a float promotes the whole expression to `Number`, and
+/// mixing in a complex promotes it to `Object::Complex`.
+///
+/// NB: `Object`'s `PartialEq` treats `Rational` against an equal-valued
+/// `Number` as equal (e.g. `Rational(4, 2) == Number(2.0)`) -- see `eval/object.rs`.
+pub(crate) fn add(a: Object, b: Object) -> Result<Object> {
+    match (a, b) {
+        (Object::Rational(n1, d1), Object::Rational(n2, d2)) => reduce(n1 * d2 + n2 * d1, d1 * d2),
+        (a, b) if is_complex(&a) || is_complex(&b) => {
+            let (re1, im1) = as_complex(a)?;
+            let (re2, im2) = as_complex(b)?;
+            Ok(Object::Complex(re1 + re2, im1 + im2))
+        }
+        (a, b) => Ok(Object::Number(as_number(a)? + as_number(b)?)),
+    }
+}
+
+pub(crate) fn subtract(a: Object, b: Object) -> Result<Object> {
+    match (a, b) {
+        (Object::Rational(n1, d1), Object::Rational(n2, d2)) => reduce(n1 * d2 - n2 * d1, d1 * d2),
+        (a, b) if is_complex(&a) || is_complex(&b) => {
+            let (re1, im1) = as_complex(a)?;
+            let (re2, im2) = as_complex(b)?;
+            Ok(Object::Complex(re1 - re2, im1 - im2))
+        }
+        (a, b) => Ok(Object::Number(as_number(a)? - as_number(b)?)),
+    }
+}
+
+pub(crate) fn multiply(a: Object, b: Object) -> Result<Object> {
+    match (a, b) {
+        (Object::Rational(n1, d1), Object::Rational(n2, d2)) => reduce(n1 * n2, d1 * d2),
+        (a, b) if is_complex(&a) || is_complex(&b) => {
+            let (re1, im1) = as_complex(a)?;
+            let (re2, im2) = as_complex(b)?;
+            Ok(Object::Complex(re1 * re2 - im1 * im2, re1 * im2 + im1 * re2))
+        }
+        (a, b) => Ok(Object::Number(as_number(a)? * as_number(b)?)),
+    }
+}
+
+/// Dividing two whole `Number`s stays exact as a reduced `Rational` instead
+/// of immediately collapsing to a lossy float. A zero divisor is always an
+/// error, matching `reduce`, rather than silently producing `inf`/`NaN`.
+pub(crate) fn divide(a: Object, b: Object) -> Result<Object> {
+    match (a, b) {
+        (Object::Number(n1), Object::Number(n2)) if is_integer(n1) && is_integer(n2) => {
+            reduce(n1 as i64, n2 as i64)
+        }
+        (Object::Rational(n1, d1), Object::Rational(n2, d2)) => {
+            if n2 == 0 {
+                return Err(Error::Eval("division by zero".into()));
+            }
+
+            reduce(n1 * d2, d1 * n2)
+        }
+        (a, b) if is_complex(&a) || is_complex(&b) => {
+            let (re1, im1) = as_complex(a)?;
+            let (re2, im2) = as_complex(b)?;
+            let denom = re2 * re2 + im2 * im2;
+
+            if denom == 0.0 {
+                return Err(Error::Eval("division by zero".into()));
+            }
+
+            Ok(Object::Complex((re1 * re2 + im1 * im2) / denom, (im1 * re2 - re1 * im2) / denom))
+        }
+        (a, b) => {
+            let divisor = as_number(b)?;
+
+            if divisor == 0.0 {
+                return Err(Error::Eval("division by zero".into()));
+            }
+
+            Ok(Object::Number(as_number(a)? / divisor))
+        }
+    }
+}
+
+/// `sqrt` of a negative number promotes to `Object::Complex` instead of `NaN`.
+pub(crate) fn sqrt(value: Object) -> Result<Object> {
+    match value {
+        Object::Number(n) if n < 0.0 => Ok(Object::Complex(0.0, (-n).sqrt())),
+        Object::Rational(n, d) if n < 0 => Ok(Object::Complex(0.0, ((-n) as f64 / d as f64).sqrt())),
+        other => Ok(Object::Number(as_number(other)?.sqrt())),
+    }
+}
+
+/// Reduces a rational to lowest terms with the sign normalized onto the numerator.
+pub(crate) fn reduce(numerator: i64, denominator: i64) -> Result<Object> {
+    if denominator == 0 {
+        return Err(Error::Eval("division by zero".into()));
+    }
+
+    let sign = if denominator < 0 { -1 } else { 1 };
+    let (numerator, denominator) = (numerator * sign, denominator * sign);
+    let divisor = gcd(numerator.abs(), denominator);
+
+    Ok(Object::Rational(numerator / divisor, denominator / divisor))
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 { a.max(1) } else { gcd(b, a % b) }
+}
+
+fn is_integer(n: f64) -> bool {
+    n.fract() == 0.0
+}
+
+fn is_complex(value: &Object) -> bool {
+    matches!(value, Object::Complex(_, _))
+}
+
+pub(crate) fn as_number(value: Object) -> Result<f64> {
+    match value {
+        Object::Number(n) => Ok(n),
+        Object::Rational(n, d) => Ok(n as f64 / d as f64),
+        _ => Err(Error::Eval("expected a real numeric value".into())),
+    }
+}
+
+fn as_complex(value: Object) -> Result<(f64, f64)> {
+    match value {
+        Object::Complex(re, im) => Ok((re, im)),
+        other => Ok((as_number(other)?, 0.0)),
+    }
+}
@@ -0,0 +1,88 @@
+use alloc::collections::BTreeMap;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use crate::error::*;
+
+#[derive(Clone)]
+pub enum Object {
+    Number(f64),
+    String(String),
+    List(Vec<Object>),
+    AssociativeArray(BTreeMap<String, Object>),
+    Rational(i64, i64),
+    Complex(f64, f64),
+    Function(Rc<dyn Fn(Vec<Object>) -> Result<Object>>),
+}
+
+impl Object {
+    pub(crate) fn function(f: impl Fn(Vec<Object>) -> Result<Object> + 'static) -> Self {
+        Object::Function(Rc::new(f))
+    }
+
+    pub(crate) fn call(&self, args: Vec<Object>) -> Result<Object> {
+        match self {
+            Object::Function(f) => f(args),
+            _ => Err(Error::Eval("value is not callable".into())),
+        }
+    }
+
+    pub(crate) fn truthy(&self) -> bool {
+        match self {
+            Object::Number(n) => *n != 0.0,
+            Object::String(s) => !s.is_empty(),
+            Object::List(items) => !items.is_empty(),
+            Object::AssociativeArray(map) => !map.is_empty(),
+            Object::Rational(n, _) => *n != 0,
+            Object::Complex(re, im) => *re != 0.0 || *im != 0.0,
+            Object::Function(_) => true,
+        }
+    }
+}
+
+impl fmt::Debug for Object {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Object::Number(n) => write!(f, "Number({})", n),
+            Object::String(s) => write!(f, "String({:?})", s),
+            Object::List(items) => write!(f, "List({:?})", items),
+            Object::AssociativeArray(map) => write!(f, "AssociativeArray({:?})", map),
+            Object::Rational(n, d) => write!(f, "Rational({}, {})", n, d),
+            Object::Complex(re, im) => write!(f, "Complex({}, {})", re, im),
+            Object::Function(_) => write!(f, "Function(..)"),
+        }
+    }
+}
+
+impl PartialEq for Object {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Object::Number(a), Object::Number(b)) => a == b,
+            (Object::String(a), Object::String(b)) => a == b,
+            (Object::List(a), Object::List(b)) => a == b,
+            (Object::AssociativeArray(a), Object::AssociativeArray(b)) => a == b,
+            (Object::Rational(n1, d1), Object::Rational(n2, d2)) => {
+                (*n1 as f64 / *d1 as f64) == (*n2 as f64 / *d2 as f64)
+            }
+            (Object::Complex(a1, b1), Object::Complex(a2, b2)) => a1 == a2 && b1 == b2,
+            // A rational compares equal to a plain number with the same value,
+            // even though one stores an exact fraction and the other an `f64`
+            // -- e.g. `Rational(4, 2) == Number(2.0)`.
+            (Object::Rational(n, d), Object::Number(x)) | (Object::Number(x), Object::Rational(n, d)) => {
+                (*n as f64 / *d as f64) == *x
+            }
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<f64> for Object {
+    fn eq(&self, other: &f64) -> bool {
+        match self {
+            Object::Number(n) => n == other,
+            Object::Rational(n, d) => (*n as f64 / *d as f64) == *other,
+            _ => false,
+        }
+    }
+}
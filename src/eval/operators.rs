@@ -0,0 +1,29 @@
+use crate::error::*;
+use crate::eval::numeric;
+use crate::Object;
+
+/// Folds over an arbitrary number of operands with the promoting two-operand
+/// logic in `numeric`, so `sum(1, 2)` and the binary `+` operator share the
+/// same arithmetic.
+pub(crate) fn add(args: &[Object]) -> Result<Object> {
+    fold(args, numeric::add)
+}
+
+pub(crate) fn subtract(args: &[Object]) -> Result<Object> {
+    fold(args, numeric::subtract)
+}
+
+pub(crate) fn multiply(args: &[Object]) -> Result<Object> {
+    fold(args, numeric::multiply)
+}
+
+pub(crate) fn divide(args: &[Object]) -> Result<Object> {
+    fold(args, numeric::divide)
+}
+
+fn fold(args: &[Object], op: impl Fn(Object, Object) -> Result<Object>) -> Result<Object> {
+    let mut values = args.iter().cloned();
+    let first = values.next().ok_or_else(|| Error::Eval("operator requires at least one operand".into()))?;
+
+    values.try_fold(first, op)
+}
@@ -0,0 +1,43 @@
+use alloc::vec::Vec;
+use crate::error::*;
+use crate::Object;
+
+/// Backing functions for the `|>`, `|:` and `|?` operators, registered in
+/// `Context::new`'s operator-handler table and dispatched as `(left, right)`
+/// the same way `+`/`-`/etc. dispatch to `numeric::add`/`numeric::subtract`.
+/// Each operator reads left-to-right (`v |> f`, `list |: f`, `list |? f`),
+/// so the operand comes first and the function second.
+
+/// `v |> f` applies `f` to `v`, equivalent to `f(v)`.
+pub(crate) fn apply(value: Object, function: Object) -> Result<Object> {
+    function.call(alloc::vec![value])
+}
+
+/// `list |: f` maps `f` over every element of `list`.
+pub(crate) fn map(list: Object, function: Object) -> Result<Object> {
+    let Object::List(values) = list else {
+        return Err(Error::Eval("`|:` can only map over a list".into()));
+    };
+
+    values.into_iter()
+        .map(|value| function.clone().call(alloc::vec![value]))
+        .collect::<Result<Vec<_>>>()
+        .map(Object::List)
+}
+
+/// `list |? f` keeps the elements of `list` for which `f` returns a truthy value.
+pub(crate) fn filter(list: Object, function: Object) -> Result<Object> {
+    let Object::List(values) = list else {
+        return Err(Error::Eval("`|?` can only filter a list".into()));
+    };
+
+    let mut kept = Vec::new();
+
+    for value in values {
+        if function.clone().call(alloc::vec![value.clone()])?.truthy() {
+            kept.push(value);
+        }
+    }
+
+    Ok(Object::List(kept))
+}
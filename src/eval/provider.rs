@@ -0,0 +1,15 @@
+use crate::Object;
+
+pub trait Row {
+    fn fields(&self) -> impl Iterator<Item = impl AsRef<str>> + Clone;
+    fn get(&self, field: &str) -> Option<Object>;
+}
+
+pub trait DataSource {
+    type Rows: Row;
+
+    fn list_columns(&self) -> impl Iterator<Item = impl AsRef<str>>;
+    fn rows(&self) -> impl Iterator<Item = Self::Rows>;
+    fn row(&self, row: usize) -> Option<Self::Rows>;
+    fn num_rows(&self) -> usize;
+}
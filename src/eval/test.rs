@@ -167,4 +167,191 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_column_address_returns_list() -> Result<()> {
+        let cx = Context::new(ManualProvider::<TwoColumns> {
+            columns: vec!["col1".to_owned(), "col2".to_owned()],
+            rows: vec![
+                TwoColumns { col1: "a".to_owned(), col2: "b".to_owned() },
+                TwoColumns { col1: "c".to_owned(), col2: "d".to_owned() },
+            ],
+        });
+
+        assert_eq!(cx.evaluate(r#"{col1}"#)?, Object::List(vec![
+            Object::String("a".to_owned()),
+            Object::String("c".to_owned()),
+        ]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_aggregate_over_column() -> Result<()> {
+        let cx = Context::new(ManualProvider::<TwoColumns> {
+            columns: vec!["col1".to_owned(), "col2".to_owned()],
+            rows: vec![
+                TwoColumns { col1: "a".to_owned(), col2: "b".to_owned() },
+                TwoColumns { col1: "c".to_owned(), col2: "d".to_owned() },
+            ],
+        });
+
+        assert_eq!(cx.evaluate(r#"count({col1})"#)?, 2.0);
+
+        Ok(())
+    }
+
+    #[derive(Clone)]
+    struct SpreadsheetRow {
+        a: f64,
+        b: f64,
+    }
+
+    impl Row for SpreadsheetRow {
+        fn fields(&self) -> impl Iterator<Item = impl AsRef<str>> + Clone {
+            vec!["A", "B"].into_iter()
+        }
+
+        fn get(&self, field: &str) -> Option<Object> {
+            match field {
+                "A" => Some(Object::Number(self.a)),
+                "B" => Some(Object::Number(self.b)),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_range_address_returns_matrix() -> Result<()> {
+        let cx = Context::new(ManualProvider::<SpreadsheetRow> {
+            columns: vec!["A".to_owned(), "B".to_owned()],
+            rows: vec![
+                SpreadsheetRow { a: 1.0, b: 2.0 },
+                SpreadsheetRow { a: 3.0, b: 4.0 },
+            ],
+        });
+
+        assert_eq!(cx.evaluate(r#"{A0:B1}"#)?, Object::List(vec![
+            Object::List(vec![Object::Number(1.0), Object::Number(2.0)]),
+            Object::List(vec![Object::Number(3.0), Object::Number(4.0)]),
+        ]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_range_address_single_column_returns_list() -> Result<()> {
+        let cx = Context::new(ManualProvider::<SpreadsheetRow> {
+            columns: vec!["A".to_owned(), "B".to_owned()],
+            rows: vec![
+                SpreadsheetRow { a: 1.0, b: 2.0 },
+                SpreadsheetRow { a: 3.0, b: 4.0 },
+            ],
+        });
+
+        assert_eq!(cx.evaluate(r#"{A0:A1}"#)?, Object::List(vec![
+            Object::Number(1.0),
+            Object::Number(3.0),
+        ]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lambda_apply_via_pipe() -> Result<()> {
+        let cx = Context::new(ManualProvider::<TwoColumns> {
+            columns: vec!["Column 1".to_owned(), "Column 2".to_owned()],
+            rows: vec![],
+        });
+
+        assert_eq!(cx.evaluate(r#"5 |> (x -> x + 1)"#)?, 6.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pipe_filter_then_map() -> Result<()> {
+        let cx = Context::new(ManualProvider::<TwoColumns> {
+            columns: vec!["Column 1".to_owned(), "Column 2".to_owned()],
+            rows: vec![],
+        }).with_global("list", Object::List(vec![
+            Object::Number(1.0),
+            Object::Number(2.0),
+            Object::Number(3.0),
+        ]));
+
+        assert_eq!(cx.evaluate(r#"list |? (x -> x > 1) |: (x -> x * 10)"#)?, Object::List(vec![
+            Object::Number(20.0),
+            Object::Number(30.0),
+        ]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rational_arithmetic_stays_exact() -> Result<()> {
+        let cx = Context::new(ManualProvider::<TwoColumns> {
+            columns: vec!["Column 1".to_owned(), "Column 2".to_owned()],
+            rows: vec![],
+        });
+
+        assert_eq!(cx.evaluate(r#"1/3 + 1/6"#)?, Object::Rational(1, 2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sqrt_of_negative_promotes_to_complex() -> Result<()> {
+        let cx = Context::new(ManualProvider::<TwoColumns> {
+            columns: vec!["Column 1".to_owned(), "Column 2".to_owned()],
+            rows: vec![],
+        }).with_global("sqrt", Object::function(|args: Vec<Object>| {
+            crate::eval::numeric::sqrt(args.into_iter().next().unwrap())
+        }));
+
+        assert_eq!(cx.evaluate(r#"sqrt(-4)"#)?, Object::Complex(0.0, 2.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_object_round_trips_through_encoding() -> Result<()> {
+        let value = Object::List(vec![
+            Object::Number(1.5),
+            Object::String("hi".to_owned()),
+            Object::Rational(3, 4),
+            Object::Complex(1.0, -2.0),
+            Object::AssociativeArray(vec![
+                ("a".to_owned(), Object::Number(2.0)),
+            ].into_iter().collect()),
+        ]);
+
+        let encoded = value.encode()?;
+        let (rest, decoded) = Object::decode(&encoded).expect("round trip decode");
+
+        assert!(rest.is_empty());
+        assert_eq!(decoded, value);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rational_compares_equal_to_matching_number() {
+        assert_eq!(Object::Rational(4, 2), Object::Number(2.0));
+    }
+
+    #[test]
+    fn test_pipe_operator_dispatches_value_then_function() -> Result<()> {
+        let cx = Context::new(ManualProvider::<TwoColumns> {
+            columns: vec!["Column 1".to_owned(), "Column 2".to_owned()],
+            rows: vec![],
+        });
+
+        let apply = cx.operator_handler("|>").expect("`|>` should be registered");
+        let increment = Object::function(|args: Vec<Object>| numeric::add(args[0].clone(), Object::Number(1.0)));
+
+        assert_eq!(apply(Object::Number(5.0), increment)?, Object::Number(6.0));
+
+        Ok(())
+    }
 }
\ No newline at end of file
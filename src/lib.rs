@@ -0,0 +1,11 @@
+#![no_std]
+
+extern crate alloc;
+
+pub(crate) mod parse;
+pub(crate) mod eval;
+pub mod error;
+
+pub use eval::context::Context;
+pub use eval::object::Object;
+pub use eval::provider::{DataSource, Row};
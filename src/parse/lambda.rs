@@ -0,0 +1,53 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use crate::parse::parser;
+use crate::parse::value::Value;
+use nom::IResult;
+
+/// `x -> expr` or `(a, b) -> expr`: an anonymous function literal that
+/// captures the defining `Context`'s globals and binds its parameters when
+/// invoked through the existing `Object::function` call machinery.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Lambda {
+    pub(crate) parameters: Vec<String>,
+    pub(crate) body: Box<Value>,
+}
+
+impl Lambda {
+    pub(super) fn parse(input: &str) -> IResult<&str, Self> {
+        parser::map(
+            parser::separated_pair(
+                parser::alt((parse_parameter_list, parse_single_parameter)),
+                parser::tuple((parser::multispace0, parser::tag("->"), parser::multispace0)),
+                Value::parse,
+            ),
+            |(parameters, body)| Lambda { parameters, body: Box::new(body) },
+        )(input)
+    }
+}
+
+fn parse_single_parameter(input: &str) -> IResult<&str, Vec<String>> {
+    parser::map(parse_identifier, |name| alloc::vec![name])(input)
+}
+
+fn parse_parameter_list(input: &str) -> IResult<&str, Vec<String>> {
+    parser::delimited(
+        parser::char('('),
+        parser::separated_list1(
+            parser::tuple((parser::multispace0, parser::char(','), parser::multispace0)),
+            parse_identifier,
+        ),
+        parser::char(')'),
+    )(input)
+}
+
+fn parse_identifier(input: &str) -> IResult<&str, String> {
+    parser::map(
+        parser::recognize(parser::pair(
+            parser::alt((parser::alpha1, parser::tag("_"))),
+            parser::many0(parser::alt((parser::alphanumeric1, parser::tag("_")))),
+        )),
+        |name: &str| name.to_owned(),
+    )(input)
+}
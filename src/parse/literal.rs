@@ -4,13 +4,15 @@ use crate::parse::key::Key;
 use crate::parse::parser;
 use alloc::format;
 use alloc::string::String;
-use alloc::vec::Vec;
 use nom::IResult;
 
 #[derive(Debug, PartialEq)]
 pub(crate) enum Literal {
     Name(String),
     Number(f64),
+    /// An imaginary literal such as `3i`; combined with a real `Number` by
+    /// the `+`/`-` operators into an `Object::Complex`.
+    Imaginary(f64),
     String(String),
     Address(Address),
 }
@@ -35,17 +37,32 @@ impl Literal {
 }
 
 fn parse_number(input: &str) -> IResult<&str, Literal> {
+    parser::alt((
+        parse_imaginary,
+        parser::map(
+            parser::alt((
+                parse_integer,
+                parse_decimal,
+                // These can fail fast because they have tags, however they are not used nearly as often as the above two.
+                parse_hex,
+                parse_oct,
+                parse_bin,
+                parse_scientific,
+            )),
+            Literal::Number,
+        ),
+    ))(input)
+}
+
+// Tried before the plain-number alternatives above, otherwise `3i` would parse
+// as `3` and leave a dangling `i` for the caller to choke on.
+fn parse_imaginary(input: &str) -> IResult<&str, Literal> {
     parser::map(
-        parser::alt((
-            parse_integer,
-            parse_decimal,
-            // These can fail fast because they have tags, however they are not used nearly as often as the above two.
-            parse_hex,
-            parse_oct,
-            parse_bin,
-            parse_scientific,
-        )),
-        Literal::Number,
+        parser::terminated(
+            parser::alt((parse_scientific, parse_decimal, parse_integer)),
+            parser::tag_no_case("i"),
+        ),
+        Literal::Imaginary,
     )(input)
 }
 
@@ -59,71 +76,46 @@ fn negative(input: &str) -> IResult<&str, &str> {
     })(input)
 }
 
-fn parse_hex(input: &str) -> IResult<&str, f64> {
-    parser::map(
-        parser::tuple((
-            negative,
-            parser::tag("0x"),
-            parser::many1(parser::alt((parser::hex_digit1, parser::tag("_")))),
-        )),
-        |(neg, _, body)| {
-            // TODO: handle parse errors properly
-
-            let body = body
-                .into_iter()
-                .flat_map(|i| i.chars())
-                .filter(|i| nom::character::is_hex_digit(*i as u8))
-                .collect::<String>();
+// Shared by parse_hex/parse_oct/parse_bin: strips the `_` digit separators
+// and hands the remaining digits to `i64::from_str_radix` with the right
+// base, surfacing overflow/invalid-digit failures as a recoverable parse
+// error instead of panicking on adversarial input.
+fn parse_radix(
+    prefix: &'static str,
+    base: u32,
+    is_digit: fn(char) -> bool,
+) -> impl Fn(&str) -> IResult<&str, f64> {
+    move |input: &str| {
+        parser::map_res(
+            parser::tuple((
+                negative,
+                parser::preceded(
+                    parser::tag(prefix),
+                    parser::recognize(parser::many1(parser::alt((
+                        parser::take_while1(is_digit),
+                        parser::tag("_"),
+                    )))),
+                ),
+            )),
+            move |(neg, body): (&str, &str)| {
+                let digits = body.chars().filter(|c| *c != '_').collect::<String>();
+
+                i64::from_str_radix(&format!("{}{}", neg, digits), base).map(|i| i as f64)
+            },
+        )(input)
+    }
+}
 
-            i64::from_str_radix(&format!("{}{}", neg, body), 2).unwrap() as f64
-        },
-    )(input)
+fn parse_hex(input: &str) -> IResult<&str, f64> {
+    parse_radix("0x", 16, |c| c.is_ascii_hexdigit())(input)
 }
 
 fn parse_oct(input: &str) -> IResult<&str, f64> {
-    parser::map(
-        parser::tuple((
-            negative,
-            parser::tag("0o"),
-            parser::many1(parser::alt((parser::oct_digit1, parser::tag("_")))),
-        )),
-        |(neg, _, body)| {
-            // TODO: handle parse errors properly
-
-            let body = body
-                .into_iter()
-                .flat_map(|i| i.chars())
-                .filter(|i| nom::character::is_oct_digit(*i as u8))
-                .collect::<String>();
-
-            i64::from_str_radix(&format!("{}{}", neg, body), 2).unwrap() as f64
-        },
-    )(input)
+    parse_radix("0o", 8, |c| ('0'..='7').contains(&c))(input)
 }
 
 fn parse_bin(input: &str) -> IResult<&str, f64> {
-    parser::map(
-        parser::tuple((
-            negative,
-            parser::tag("0b"),
-            parser::many1(parser::alt((
-                parser::char('1'),
-                parser::char('0'),
-                parser::char('_'),
-            ))),
-        )),
-        |(neg, _, body): (&str, &str, Vec<char>)| {
-            // TODO: handle parse errors properly
-
-            let body = neg
-                .chars()
-                .chain(body.into_iter())
-                .filter(|i| *i != '_')
-                .collect::<String>();
-
-            i64::from_str_radix(&body, 2).unwrap() as f64
-        },
-    )(input)
+    parse_radix("0b", 2, |c| c == '0' || c == '1')(input)
 }
 
 fn parse_float(input: &str) -> IResult<&str, String> {
@@ -139,41 +131,37 @@ fn parse_float(input: &str) -> IResult<&str, String> {
 }
 
 fn parse_decimal(input: &str) -> IResult<&str, f64> {
-    parser::map(parse_float, |float| float.parse().unwrap())(input)
+    parser::map_res(parse_float, |float| float.parse::<f64>())(input)
 }
 
 fn parse_scientific(input: &str) -> IResult<&str, f64> {
-    parser::map(
+    parser::map_res(
         parser::tuple((parse_float, parser::tag_no_case("e"), parse_float)),
-        |(base, _, exponent)| {
-            // TODO: handle parse errors properly
-            let base = base.parse::<f64>().unwrap();
-            let exponent = exponent.parse::<f64>().unwrap();
+        |(base, _, exponent)| -> core::result::Result<f64, core::num::ParseFloatError> {
+            let base = base.parse::<f64>()?;
+            let exponent = exponent.parse::<f64>()?;
 
-            base * 10.0f64.powf(exponent)
+            Ok(base * 10.0f64.powf(exponent))
         },
     )(input)
 }
 
 fn parse_integer(input: &str) -> IResult<&str, f64> {
-    parser::map(parser::tuple((negative, parser::digit1)), |(neg, num)| {
-        num.parse::<i64>()
-            .map(|i| {
-                if neg.len() > 0 {
-                    (i * -1) as f64
-                } else {
-                    i as f64
-                }
-            })
-            // TODO: Handle errors properly
-            .unwrap()
-    })(input)
+    parser::map_res(
+        parser::tuple((negative, parser::digit1)),
+        |(neg, num): (&str, &str)| {
+            num.parse::<i64>()
+                .map(|i| if neg.len() > 0 { (i * -1) as f64 } else { i as f64 })
+        },
+    )(input)
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Address {
     pub column: Column,
     pub row: Option<String>,
+    /// The `end` endpoint of a `{start:end}` range address, e.g. `{A1:C3}`.
+    pub end: Option<(Column, Option<String>)>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -188,15 +176,25 @@ impl Address {
             parser::delimited(
                 parser::char('{'),
                 parser::tuple((
-                    parser::alt((
-                        parser::map(parse_string('{', '}'), |frag| Column::Name(frag)),
-                        parser::map(parser::alpha1, |col: &str| Column::Number(col.into())),
-                    )),
-                    parser::opt(parser::digit1),
+                    Self::parse_endpoint,
+                    parser::opt(parser::preceded(parser::char(':'), Self::parse_endpoint)),
                 )),
                 parser::char('}'),
             ),
-            |(column, row): (Column, Option<&str>)| Address { column, row: row.map(ToOwned::to_owned) },
+            |((column, row), end)| Address { column, row, end },
+        )(input)
+    }
+
+    fn parse_endpoint(input: &str) -> IResult<&str, (Column, Option<String>)> {
+        parser::map(
+            parser::tuple((
+                parser::alt((
+                    parser::map(parse_string('{', '}'), |frag| Column::Name(frag)),
+                    parser::map(parser::alpha1, |col: &str| Column::Number(col.into())),
+                )),
+                parser::opt(parser::digit1),
+            )),
+            |(column, row): (Column, Option<&str>)| (column, row.map(ToOwned::to_owned)),
         )(input)
     }
 }
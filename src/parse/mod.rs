@@ -2,6 +2,7 @@ pub(crate) mod key;
 pub(crate) mod call;
 pub(crate) mod list;
 pub(crate) mod literal;
+pub(crate) mod lambda;
 pub(crate) mod associative_array;
 pub(crate) mod expression;
 pub(crate) mod value;
@@ -24,6 +25,7 @@ pub(super) mod parser {
     pub use nom::character::complete::*;
     pub use nom::combinator::*;
     pub use nom::multi::*;
+    pub use nom::number::complete::*;
     pub use nom::sequence::*;
 }
 
@@ -33,6 +35,7 @@ pub(crate) mod objects {
     pub(crate) use crate::parse::expression::Expression;
     pub(crate) use crate::parse::call::Call;
     pub(crate) use crate::parse::key::Key;
+    pub(crate) use crate::parse::lambda::Lambda;
 }
 
 